@@ -0,0 +1,294 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::Dataset;
+
+use super::{decode_wav, ChannelMode};
+
+/// An item yielded by [AudioFolderDataset]: decoded audio samples with a class label.
+#[derive(Clone, Debug)]
+pub struct AudioFolderItem {
+    /// Audio samples in the range [-1.0, 1.0].
+    pub audio_samples: Vec<f32>,
+
+    /// The sample rate of the audio.
+    pub sample_rate: usize,
+
+    /// Index into [AudioFolderDataset::classes].
+    pub label: usize,
+}
+
+/// Which part of a ratio-based split an [AudioFolderDataset] should keep. See
+/// [AudioFolderSplit::Ratio].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SplitPart {
+    Train,
+    Validation,
+    Test,
+}
+
+/// How an [AudioFolderDataset] determines which files of the corpus belong to it.
+pub enum AudioFolderSplit {
+    /// Each class folder has its own split subfolder, e.g. `root/<class>/<name>/*.wav`. Use this
+    /// when the corpus already ships a fixed split.
+    Subdirectory(String),
+
+    /// Files live directly under `root/<class>/*.wav`, with the split computed on the fly:
+    /// each file is deterministically assigned to train/validation/test by hashing its path
+    /// together with `seed`, so the split is stable across runs without being stored on disk.
+    Ratio {
+        part: SplitPart,
+        train_ratio: f64,
+        val_ratio: f64,
+        seed: u64,
+    },
+}
+
+/// A generic audio classification dataset backed by a directory of `.wav`/`.flac` files, one
+/// subfolder per class.
+///
+/// Unlike [SpeechCommandsDataset](super::SpeechCommandsDataset), which is hard-wired to the
+/// Huggingface Speech Commands download, this reads any locally stored corpus organized as
+/// `root/<class>/*.{wav,flac}`.
+pub struct AudioFolderDataset {
+    entries: Vec<AudioFolderEntry>,
+    classes: Vec<String>,
+    channel_mode: ChannelMode,
+}
+
+struct AudioFolderEntry {
+    path: PathBuf,
+    label: usize,
+}
+
+impl AudioFolderDataset {
+    /// Scan `root` and build a dataset for the given split, downmixing multi-channel audio to
+    /// mono.
+    pub fn new(root: impl AsRef<Path>, split: AudioFolderSplit) -> Self {
+        Self::with_channel_mode(root, split, ChannelMode::DownmixToMono)
+    }
+
+    /// Like [Self::new], with explicit control over how multi-channel audio is handled.
+    pub fn with_channel_mode(
+        root: impl AsRef<Path>,
+        split: AudioFolderSplit,
+        channel_mode: ChannelMode,
+    ) -> Self {
+        let root = root.as_ref();
+
+        let mut classes: Vec<String> = fs::read_dir(root)
+            .unwrap()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().is_dir())
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .collect();
+        classes.sort();
+
+        let mut entries = Vec::new();
+        for (label, class) in classes.iter().enumerate() {
+            let class_dir = root.join(class);
+            let scan_dir = match &split {
+                AudioFolderSplit::Subdirectory(name) => class_dir.join(name),
+                AudioFolderSplit::Ratio { .. } => class_dir.clone(),
+            };
+
+            for path in audio_files(&scan_dir) {
+                if let AudioFolderSplit::Ratio {
+                    part,
+                    train_ratio,
+                    val_ratio,
+                    seed,
+                } = &split
+                {
+                    if split_part(&path, *seed, *train_ratio, *val_ratio) != *part {
+                        continue;
+                    }
+                }
+
+                entries.push(AudioFolderEntry { path, label });
+            }
+        }
+
+        Self {
+            entries,
+            classes,
+            channel_mode,
+        }
+    }
+
+    /// The class names, in the same order as the label indices they're assigned.
+    pub fn classes(&self) -> &[String] {
+        &self.classes
+    }
+
+    /// The number of classes found under the root directory.
+    pub fn num_classes(&self) -> usize {
+        self.classes.len()
+    }
+}
+
+impl Dataset<AudioFolderItem> for AudioFolderDataset {
+    fn get(&self, index: usize) -> Option<AudioFolderItem> {
+        let entry = self.entries.get(index)?;
+
+        let (audio_samples, sample_rate) = match entry.path.extension().and_then(|ext| ext.to_str())
+        {
+            Some(ext) if ext.eq_ignore_ascii_case("flac") => {
+                decode_flac(&entry.path, self.channel_mode)
+            }
+            _ => {
+                let bytes = fs::read(&entry.path).unwrap();
+                decode_wav(&bytes, self.channel_mode)
+            }
+        };
+
+        Some(AudioFolderItem {
+            audio_samples,
+            sample_rate,
+            label: entry.label,
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+/// List `.wav`/`.flac` files directly under `dir`, sorted for deterministic ordering.
+fn audio_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut files: Vec<PathBuf> = read_dir
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| ext.eq_ignore_ascii_case("wav") || ext.eq_ignore_ascii_case("flac"))
+                .unwrap_or(false)
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Deterministically assign `path` to a split part, by hashing the path together with `seed`
+/// into a pseudo-random fraction in `[0.0, 1.0)`.
+///
+/// Uses a fixed-algorithm FNV-1a hash rather than [std::collections::hash_map::DefaultHasher]:
+/// `DefaultHasher`'s algorithm is an unspecified implementation detail that can change between
+/// Rust versions, which would silently reshuffle the split for a fixed `seed` across toolchain
+/// upgrades.
+fn split_part(path: &Path, seed: u64, train_ratio: f64, val_ratio: f64) -> SplitPart {
+    let hash = fnv1a_64(seed, path.to_string_lossy().as_bytes());
+    let fraction = (hash >> 11) as f64 / (1u64 << 53) as f64;
+
+    if fraction < train_ratio {
+        SplitPart::Train
+    } else if fraction < train_ratio + val_ratio {
+        SplitPart::Validation
+    } else {
+        SplitPart::Test
+    }
+}
+
+/// FNV-1a hash of `seed`'s bytes followed by `bytes`, fixed across Rust versions (unlike
+/// [std::collections::hash_map::DefaultHasher]).
+fn fnv1a_64(seed: u64, bytes: &[u8]) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const FNV_PRIME: u64 = 0x100000001b3;
+
+    let mut hash = FNV_OFFSET_BASIS;
+    for byte in seed.to_le_bytes().iter().chain(bytes) {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Decode a FLAC file into samples of floats [-1.0, 1.0] and its sample rate.
+fn decode_flac(path: &Path, channel_mode: ChannelMode) -> (Vec<f32>, usize) {
+    let mut reader = claxon::FlacReader::open(path).unwrap();
+    let info = reader.streaminfo();
+    let max_value = (1i64 << (info.bits_per_sample - 1)) as f32;
+    let channels = info.channels as usize;
+
+    let samples: Vec<f32> = reader
+        .samples()
+        .filter_map(Result::ok)
+        .map(|sample| sample as f32 / max_value)
+        .collect();
+
+    let audio_samples = match (channel_mode, channels) {
+        (ChannelMode::DownmixToMono, channels) if channels > 1 => samples
+            .chunks(channels)
+            .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+            .collect(),
+        _ => samples,
+    };
+
+    (audio_samples, info.sample_rate as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fnv1a_64_is_deterministic_for_the_same_inputs() {
+        let a = fnv1a_64(42, b"clip.wav");
+        let b = fnv1a_64(42, b"clip.wav");
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn fnv1a_64_differs_across_seeds_and_bytes() {
+        let base = fnv1a_64(42, b"clip.wav");
+        assert_ne!(base, fnv1a_64(43, b"clip.wav"));
+        assert_ne!(base, fnv1a_64(42, b"other.wav"));
+    }
+
+    #[test]
+    fn split_part_is_deterministic_for_the_same_path_and_seed() {
+        let path = Path::new("root/class/clip.wav");
+        let first = split_part(path, 7, 0.8, 0.1);
+        let second = split_part(path, 7, 0.8, 0.1);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn split_part_respects_ratio_boundaries() {
+        // Ratios of 1.0/0.0 mean every path must land in Train.
+        let paths = [
+            "root/a/1.wav",
+            "root/a/2.wav",
+            "root/b/1.wav",
+            "root/b/2.wav",
+        ];
+        for path in paths {
+            assert_eq!(split_part(Path::new(path), 1, 1.0, 0.0), SplitPart::Train);
+        }
+    }
+
+    #[test]
+    fn split_part_distributes_many_paths_across_all_three_parts() {
+        let mut counts = [0usize; 3];
+        for i in 0..3000 {
+            let path = PathBuf::from(format!("root/class/clip_{i}.wav"));
+            let part = split_part(&path, 0, 0.8, 0.1);
+            match part {
+                SplitPart::Train => counts[0] += 1,
+                SplitPart::Validation => counts[1] += 1,
+                SplitPart::Test => counts[2] += 1,
+            }
+        }
+
+        // With 3000 samples and an 80/10/10 split, each bucket should be in the right ballpark;
+        // this mainly guards against a degenerate hash that always lands in one bucket.
+        assert!(counts[0] > 2000 && counts[0] < 2800);
+        assert!(counts[1] > 100 && counts[1] < 500);
+        assert!(counts[2] > 100 && counts[2] < 500);
+    }
+}
@@ -0,0 +1,13 @@
+mod audio_folder;
+mod pad_or_crop;
+mod resample;
+mod spectrogram;
+mod speech_commands;
+mod trim_silence;
+
+pub use audio_folder::*;
+pub use pad_or_crop::*;
+pub use resample::*;
+pub use spectrogram::*;
+pub use speech_commands::*;
+pub use trim_silence::*;
@@ -0,0 +1,240 @@
+use rand::Rng;
+
+use crate::transform::Mapper;
+
+use super::SpeechItem;
+
+/// How [PadOrCrop] positions the kept/padded region relative to the original clip.
+#[derive(Clone, Copy, Debug)]
+pub enum PadOrCropMode {
+    /// Keep/pad around the center of the clip.
+    Center,
+
+    /// Keep/pad starting from the beginning of the clip.
+    Left,
+
+    /// Keep/pad ending at the end of the clip.
+    Right,
+
+    /// Crop from a random offset (cheap augmentation); padding behaves like [PadOrCropMode::Center].
+    Random,
+}
+
+/// Mapper padding or cropping a [SpeechItem]'s audio samples to a fixed length, so clips of
+/// varying length can be collated into a single tensor.
+pub struct PadOrCrop {
+    target_len: usize,
+    mode: PadOrCropMode,
+    pad_value: f32,
+}
+
+/// A [SpeechItem] padded or cropped to a fixed length, with the number of valid (non-padded)
+/// samples recorded so sequence models can ignore padding.
+#[derive(Clone, Debug)]
+pub struct PaddedSpeechItem {
+    /// Audio samples, exactly `target_len` long.
+    pub audio_samples: Vec<f32>,
+
+    /// The sample rate of the audio.
+    pub sample_rate: usize,
+
+    /// Number of samples that come from the original clip, as opposed to padding. Equal to
+    /// `audio_samples.len()` when the original clip was cropped rather than padded.
+    pub valid_len: usize,
+
+    /// 20 target words, silence and other
+    pub label: super::SpeechCommandClass,
+
+    /// The original label for debugging and remapping if needed.
+    pub label_original: super::SpeechCommandClass,
+}
+
+impl PadOrCrop {
+    /// Create a new mapper bringing every clip to exactly `target_len` samples.
+    pub fn new(target_len: usize, mode: PadOrCropMode, pad_value: f32) -> Self {
+        Self {
+            target_len,
+            mode,
+            pad_value,
+        }
+    }
+
+    /// Create a new mapper bringing every clip to `target_ms` milliseconds at `sample_rate` Hz.
+    pub fn from_millis(
+        target_ms: usize,
+        sample_rate: usize,
+        mode: PadOrCropMode,
+        pad_value: f32,
+    ) -> Self {
+        Self::new(target_ms * sample_rate / 1000, mode, pad_value)
+    }
+}
+
+impl Mapper<SpeechItem, PaddedSpeechItem> for PadOrCrop {
+    fn map(&self, item: &SpeechItem) -> PaddedSpeechItem {
+        let source = &item.audio_samples;
+        let valid_len = usize::min(source.len(), self.target_len);
+
+        let audio_samples = if source.len() >= self.target_len {
+            crop(source, self.target_len, self.mode)
+        } else {
+            pad(source, self.target_len, self.mode, self.pad_value)
+        };
+
+        PaddedSpeechItem {
+            audio_samples,
+            sample_rate: item.sample_rate,
+            valid_len,
+            label: item.label,
+            label_original: item.label_original,
+        }
+    }
+}
+
+fn crop(source: &[f32], target_len: usize, mode: PadOrCropMode) -> Vec<f32> {
+    let max_start = source.len() - target_len;
+    let start = match mode {
+        PadOrCropMode::Left => 0,
+        PadOrCropMode::Right => max_start,
+        PadOrCropMode::Center => max_start / 2,
+        PadOrCropMode::Random => rand::thread_rng().gen_range(0..=max_start),
+    };
+
+    source[start..start + target_len].to_vec()
+}
+
+fn pad(source: &[f32], target_len: usize, mode: PadOrCropMode, pad_value: f32) -> Vec<f32> {
+    let total_pad = target_len - source.len();
+    let left_pad = match mode {
+        PadOrCropMode::Left => 0,
+        PadOrCropMode::Right => total_pad,
+        PadOrCropMode::Center | PadOrCropMode::Random => total_pad / 2,
+    };
+
+    let mut audio_samples = vec![pad_value; target_len];
+    audio_samples[left_pad..left_pad + source.len()].copy_from_slice(source);
+    audio_samples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(audio_samples: Vec<f32>) -> SpeechItem {
+        SpeechItem {
+            audio_samples,
+            sample_rate: 16000,
+            label: super::super::SpeechCommandClass::Yes,
+            label_original: super::super::SpeechCommandClass::Yes,
+        }
+    }
+
+    #[test]
+    fn crop_left_keeps_the_first_target_len_samples() {
+        let source: Vec<f32> = (0..10).map(|n| n as f32).collect();
+        assert_eq!(
+            crop(&source, 4, PadOrCropMode::Left),
+            vec![0.0, 1.0, 2.0, 3.0]
+        );
+    }
+
+    #[test]
+    fn crop_right_keeps_the_last_target_len_samples() {
+        let source: Vec<f32> = (0..10).map(|n| n as f32).collect();
+        assert_eq!(
+            crop(&source, 4, PadOrCropMode::Right),
+            vec![6.0, 7.0, 8.0, 9.0]
+        );
+    }
+
+    #[test]
+    fn crop_center_keeps_the_middle_target_len_samples() {
+        let source: Vec<f32> = (0..10).map(|n| n as f32).collect();
+        assert_eq!(
+            crop(&source, 4, PadOrCropMode::Center),
+            vec![3.0, 4.0, 5.0, 6.0]
+        );
+    }
+
+    #[test]
+    fn crop_when_target_len_equals_source_len_is_a_no_op() {
+        let source: Vec<f32> = (0..10).map(|n| n as f32).collect();
+        for mode in [
+            PadOrCropMode::Left,
+            PadOrCropMode::Right,
+            PadOrCropMode::Center,
+        ] {
+            assert_eq!(crop(&source, 10, mode), source);
+        }
+    }
+
+    #[test]
+    fn crop_to_zero_target_len_is_empty() {
+        let source: Vec<f32> = (0..10).map(|n| n as f32).collect();
+        assert!(crop(&source, 0, PadOrCropMode::Center).is_empty());
+    }
+
+    #[test]
+    fn pad_left_puts_padding_after_the_source() {
+        let source = vec![1.0, 2.0];
+        let padded = pad(&source, 5, PadOrCropMode::Left, 0.0);
+        assert_eq!(padded, vec![1.0, 2.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn pad_right_puts_padding_before_the_source() {
+        let source = vec![1.0, 2.0];
+        let padded = pad(&source, 5, PadOrCropMode::Right, 0.0);
+        assert_eq!(padded, vec![0.0, 0.0, 0.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn pad_center_splits_padding_on_both_sides() {
+        let source = vec![1.0, 2.0];
+        let padded = pad(&source, 6, PadOrCropMode::Center, 0.0);
+        assert_eq!(padded, vec![0.0, 0.0, 1.0, 2.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn pad_when_target_len_equals_source_len_is_a_no_op() {
+        let source = vec![1.0, 2.0, 3.0];
+        assert_eq!(pad(&source, 3, PadOrCropMode::Left, 0.0), source);
+    }
+
+    #[test]
+    fn map_reports_valid_len_for_a_cropped_clip() {
+        let mapper = PadOrCrop::new(4, PadOrCropMode::Left, 0.0);
+        let result = mapper.map(&item((0..10).map(|n| n as f32).collect()));
+
+        assert_eq!(result.audio_samples.len(), 4);
+        assert_eq!(result.valid_len, 4);
+    }
+
+    #[test]
+    fn map_reports_valid_len_for_a_padded_clip() {
+        let mapper = PadOrCrop::new(10, PadOrCropMode::Left, 0.0);
+        let result = mapper.map(&item(vec![1.0, 2.0]));
+
+        assert_eq!(result.audio_samples.len(), 10);
+        assert_eq!(result.valid_len, 2);
+    }
+
+    #[test]
+    fn map_exact_length_clip_is_unchanged() {
+        let mapper = PadOrCrop::new(4, PadOrCropMode::Left, 0.0);
+        let source = vec![1.0, 2.0, 3.0, 4.0];
+        let result = mapper.map(&item(source.clone()));
+
+        assert_eq!(result.audio_samples, source);
+        assert_eq!(result.valid_len, 4);
+    }
+
+    #[test]
+    fn map_zero_target_len_yields_empty_output() {
+        let mapper = PadOrCrop::new(0, PadOrCropMode::Left, 0.0);
+        let result = mapper.map(&item(vec![1.0, 2.0, 3.0]));
+
+        assert!(result.audio_samples.is_empty());
+        assert_eq!(result.valid_len, 0);
+    }
+}
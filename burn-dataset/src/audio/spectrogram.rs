@@ -0,0 +1,398 @@
+use crate::transform::Mapper;
+
+use rustfft::{num_complex::Complex, FftPlanner};
+
+use super::{SpeechCommandClass, SpeechItem};
+
+/// Configuration for computing a mel spectrogram or MFCC feature matrix from raw audio samples.
+#[derive(Clone, Debug)]
+pub struct SpectrogramConfig {
+    /// Size of the FFT window (samples), including zero-padding.
+    pub n_fft: usize,
+
+    /// Number of samples between the start of consecutive frames.
+    pub hop_length: usize,
+
+    /// Number of samples in each analysis window, before zero-padding to `n_fft`.
+    pub win_length: usize,
+
+    /// Number of mel filterbank bands.
+    pub n_mels: usize,
+
+    /// Number of MFCC coefficients to keep (ignored for mel-spectrogram output).
+    pub n_mfcc: usize,
+
+    /// Lowest frequency (Hz) of the mel filterbank.
+    pub f_min: f32,
+
+    /// Highest frequency (Hz) of the mel filterbank.
+    pub f_max: f32,
+
+    /// Apply a pre-emphasis filter `y[n] = x[n] - coefficient * x[n - 1]` before framing.
+    pub pre_emphasis: Option<f32>,
+}
+
+impl Default for SpectrogramConfig {
+    fn default() -> Self {
+        Self {
+            n_fft: 512,
+            hop_length: 160,
+            win_length: 400,
+            n_mels: 40,
+            n_mfcc: 13,
+            f_min: 0.0,
+            f_max: 8000.0,
+            pre_emphasis: Some(0.97),
+        }
+    }
+}
+
+/// A feature matrix (e.g. mel-spectrogram or MFCC) computed from a [SpeechItem], together with
+/// its class label.
+#[derive(Clone, Debug)]
+pub struct SpeechFeatureItem {
+    /// Feature matrix, indexed as `features[frame][coefficient]`.
+    pub features: Vec<Vec<f32>>,
+
+    /// Number of frames (rows) in `features`, i.e. `features.len()`.
+    pub n_frames: usize,
+
+    /// 20 target words, silence and other
+    pub label: SpeechCommandClass,
+
+    /// The original label for debugging and remapping if needed.
+    pub label_original: SpeechCommandClass,
+}
+
+const LOG_EPS: f32 = 1e-10;
+
+/// Mapper computing a log-mel spectrogram from a [SpeechItem]'s audio samples.
+pub struct ComputeMelSpectrogram {
+    config: SpectrogramConfig,
+}
+
+impl ComputeMelSpectrogram {
+    /// Create a new mapper with the given configuration.
+    pub fn new(config: SpectrogramConfig) -> Self {
+        Self { config }
+    }
+}
+
+/// Mapper computing MFCCs (Mel-Frequency Cepstral Coefficients) from a [SpeechItem]'s audio
+/// samples.
+pub struct ComputeMfcc {
+    config: SpectrogramConfig,
+}
+
+impl ComputeMfcc {
+    /// Create a new mapper with the given configuration.
+    pub fn new(config: SpectrogramConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Mapper<SpeechItem, SpeechFeatureItem> for ComputeMelSpectrogram {
+    fn map(&self, item: &SpeechItem) -> SpeechFeatureItem {
+        let mel_energies = mel_spectrogram(&item.audio_samples, item.sample_rate, &self.config);
+        let n_frames = mel_energies.len();
+
+        SpeechFeatureItem {
+            features: mel_energies,
+            n_frames,
+            label: item.label,
+            label_original: item.label_original,
+        }
+    }
+}
+
+impl Mapper<SpeechItem, SpeechFeatureItem> for ComputeMfcc {
+    fn map(&self, item: &SpeechItem) -> SpeechFeatureItem {
+        let mel_energies = mel_spectrogram(&item.audio_samples, item.sample_rate, &self.config);
+        let features: Vec<Vec<f32>> = mel_energies
+            .iter()
+            .map(|frame| dct2(frame, self.config.n_mfcc))
+            .collect();
+        let n_frames = features.len();
+
+        SpeechFeatureItem {
+            features,
+            n_frames,
+            label: item.label,
+            label_original: item.label_original,
+        }
+    }
+}
+
+/// Compute the log-mel spectrogram of `samples`, returning one row of `n_mels` energies per
+/// frame.
+fn mel_spectrogram(
+    samples: &[f32],
+    sample_rate: usize,
+    config: &SpectrogramConfig,
+) -> Vec<Vec<f32>> {
+    let samples = match config.pre_emphasis {
+        Some(coefficient) => pre_emphasize(samples, coefficient),
+        None => samples.to_vec(),
+    };
+
+    let window = hann_window(config.win_length);
+    let filterbank = mel_filterbank(sample_rate, config);
+
+    let mut planner = FftPlanner::<f32>::new();
+    let fft = planner.plan_fft_forward(config.n_fft);
+
+    frame_signal(&samples, config.win_length, config.hop_length)
+        .map(|frame| {
+            let mut buffer: Vec<Complex<f32>> = frame
+                .iter()
+                .zip(window.iter())
+                .map(|(sample, w)| Complex::new(sample * w, 0.0))
+                .chain(std::iter::repeat(Complex::new(0.0, 0.0)))
+                .take(config.n_fft)
+                .collect();
+
+            fft.process(&mut buffer);
+
+            // Power spectrum of the non-redundant half (real input => conjugate-symmetric FFT).
+            let power_spectrum: Vec<f32> = buffer[..=config.n_fft / 2]
+                .iter()
+                .map(|bin| bin.norm_sqr())
+                .collect();
+
+            filterbank
+                .iter()
+                .map(|filter| {
+                    let energy: f32 = filter
+                        .iter()
+                        .zip(power_spectrum.iter())
+                        .map(|(weight, power)| weight * power)
+                        .sum();
+                    (energy + LOG_EPS).ln()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Apply the pre-emphasis filter `y[n] = x[n] - coefficient * x[n - 1]`.
+fn pre_emphasize(samples: &[f32], coefficient: f32) -> Vec<f32> {
+    if samples.is_empty() {
+        return Vec::new();
+    }
+
+    let mut out = Vec::with_capacity(samples.len());
+    out.push(samples[0]);
+    for window in samples.windows(2) {
+        out.push(window[1] - coefficient * window[0]);
+    }
+    out
+}
+
+/// Split `samples` into overlapping frames of `win_length`, `hop_length` apart. Only whole frames
+/// are produced: trailing samples that do not fill a frame are dropped, and zero-padding is applied
+/// only when `samples` is shorter than `win_length` (yielding a single frame).
+fn frame_signal(
+    samples: &[f32],
+    win_length: usize,
+    hop_length: usize,
+) -> impl Iterator<Item = Vec<f32>> + '_ {
+    let n_frames = if samples.len() >= win_length {
+        1 + (samples.len() - win_length) / hop_length
+    } else {
+        1
+    };
+
+    (0..n_frames).map(move |i| {
+        let start = i * hop_length;
+        let end = usize::min(start + win_length, samples.len());
+
+        let mut frame = vec![0.0; win_length];
+        if start < samples.len() {
+            frame[..end - start].copy_from_slice(&samples[start..end]);
+        }
+        frame
+    })
+}
+
+/// Periodic Hann window of the given length.
+fn hann_window(length: usize) -> Vec<f32> {
+    if length == 0 {
+        return Vec::new();
+    }
+
+    (0..length)
+        .map(|n| 0.5 - 0.5 * (2.0 * std::f32::consts::PI * n as f32 / length as f32).cos())
+        .collect()
+}
+
+fn hz_to_mel(hz: f32) -> f32 {
+    2595.0 * (1.0 + hz / 700.0).log10()
+}
+
+fn mel_to_hz(mel: f32) -> f32 {
+    700.0 * (10f32.powf(mel / 2595.0) - 1.0)
+}
+
+/// Build `n_mels` overlapping triangular filters spaced evenly on the mel scale, expressed as
+/// weights over the `n_fft / 2 + 1` power-spectrum bins.
+fn mel_filterbank(sample_rate: usize, config: &SpectrogramConfig) -> Vec<Vec<f32>> {
+    let n_bins = config.n_fft / 2 + 1;
+
+    let mel_min = hz_to_mel(config.f_min);
+    let mel_max = hz_to_mel(config.f_max);
+
+    // n_mels + 2 edges delimiting n_mels triangular filters.
+    let mel_points: Vec<f32> = (0..config.n_mels + 2)
+        .map(|i| mel_min + (mel_max - mel_min) * i as f32 / (config.n_mels + 1) as f32)
+        .collect();
+
+    let bin_points: Vec<usize> = mel_points
+        .iter()
+        .map(|mel| {
+            let hz = mel_to_hz(*mel);
+            let bin = (hz * config.n_fft as f32 / sample_rate as f32).floor() as isize;
+            bin.clamp(0, n_bins as isize - 1) as usize
+        })
+        .collect();
+
+    (0..config.n_mels)
+        .map(|m| {
+            let mut filter = vec![0.0; n_bins];
+            let (left, center, right) = (bin_points[m], bin_points[m + 1], bin_points[m + 2]);
+
+            for bin in left..center {
+                if center > left {
+                    filter[bin] = (bin - left) as f32 / (center - left) as f32;
+                }
+            }
+            for bin in center..right {
+                if right > center {
+                    filter[bin] = (right - bin) as f32 / (right - center) as f32;
+                }
+            }
+
+            filter
+        })
+        .collect()
+}
+
+/// Apply a DCT-II to `log_mel_energies` and keep the first `n_coefficients` coefficients.
+fn dct2(log_mel_energies: &[f32], n_coefficients: usize) -> Vec<f32> {
+    let n = log_mel_energies.len();
+
+    (0..n_coefficients)
+        .map(|k| {
+            let sum: f32 = log_mel_energies
+                .iter()
+                .enumerate()
+                .map(|(i, energy)| {
+                    energy
+                        * (std::f32::consts::PI * k as f32 * (2.0 * i as f32 + 1.0)
+                            / (2.0 * n as f32))
+                            .cos()
+                })
+                .sum();
+            sum * 2.0
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_signal_counts_frames_from_length_and_hop() {
+        let samples = vec![0.0; 1000];
+        let frames: Vec<Vec<f32>> = frame_signal(&samples, 400, 160).collect();
+
+        // 1 + (1000 - 400) / 160 = 4, each frame exactly win_length long.
+        assert_eq!(frames.len(), 4);
+        assert!(frames.iter().all(|frame| frame.len() == 400));
+    }
+
+    #[test]
+    fn frame_signal_zero_pads_final_frame() {
+        let samples = vec![1.0; 50];
+        let frames: Vec<Vec<f32>> = frame_signal(&samples, 100, 50).collect();
+
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].len(), 100);
+        assert!(frames[0][..50].iter().all(|&s| s == 1.0));
+        assert!(frames[0][50..].iter().all(|&s| s == 0.0));
+    }
+
+    #[test]
+    fn hann_window_is_zero_at_start_and_peaks_in_the_middle() {
+        let window = hann_window(400);
+
+        assert_eq!(window.len(), 400);
+        assert!(window[0].abs() < 1e-6);
+        assert!(window[200] > 0.99);
+    }
+
+    #[test]
+    fn hz_mel_roundtrip() {
+        for hz in [0.0, 440.0, 1000.0, 8000.0] {
+            let mel = hz_to_mel(hz);
+            assert!((mel_to_hz(mel) - hz).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn mel_filterbank_filters_are_bounded_and_nonnegative() {
+        let config = SpectrogramConfig::default();
+        let filterbank = mel_filterbank(16000, &config);
+
+        assert_eq!(filterbank.len(), config.n_mels);
+        for filter in &filterbank {
+            assert_eq!(filter.len(), config.n_fft / 2 + 1);
+            assert!(filter.iter().all(|&w| (0.0..=1.0).contains(&w)));
+        }
+    }
+
+    #[test]
+    fn dct2_keeps_requested_coefficient_count() {
+        let log_mel_energies = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let coefficients = dct2(&log_mel_energies, 3);
+
+        assert_eq!(coefficients.len(), 3);
+    }
+
+    #[test]
+    fn mel_spectrogram_highlights_the_bin_containing_a_pure_tone() {
+        let sample_rate = 16000;
+        let config = SpectrogramConfig {
+            pre_emphasis: None,
+            ..SpectrogramConfig::default()
+        };
+
+        // A 1kHz tone, long enough for several frames.
+        let freq = 1000.0;
+        let samples: Vec<f32> = (0..sample_rate)
+            .map(|n| (2.0 * std::f32::consts::PI * freq * n as f32 / sample_rate as f32).sin())
+            .collect();
+
+        let mel_energies = mel_spectrogram(&samples, sample_rate as usize, &config);
+        assert!(!mel_energies.is_empty());
+
+        let filterbank = mel_filterbank(sample_rate as usize, &config);
+        let tone_bin = (freq * config.n_fft as f32 / sample_rate as f32) as usize;
+        let tone_mel = filterbank
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a[tone_bin].partial_cmp(&b[tone_bin]).unwrap())
+            .map(|(i, _)| i)
+            .unwrap();
+
+        let frame = &mel_energies[mel_energies.len() / 2];
+        let max_mel = frame
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .map(|(i, _)| i)
+            .unwrap();
+
+        assert_eq!(max_mel, tone_mel);
+    }
+}
@@ -3,10 +3,23 @@ use crate::{
     Dataset, HuggingfaceDatasetLoader, SqliteDataset,
 };
 
-use hound::WavReader;
+use hound::{SampleFormat, WavReader};
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, FromRepr};
 
+use super::Resample;
+
+/// How multi-channel WAV files are handled when decoded into a [SpeechItem].
+#[derive(Clone, Copy, Debug, Default)]
+pub enum ChannelMode {
+    /// Downmix all channels to mono by averaging them.
+    #[default]
+    DownmixToMono,
+
+    /// Keep all channels, interleaved as in the source WAV (`[ch0, ch1, ch0, ch1, ...]`).
+    Interleaved,
+}
+
 type MappedDataset = MapperDataset<SqliteDataset<SpeechItemRaw>, ConvertSamples, SpeechItemRaw>;
 
 /// Enum representing speech command classes in the Speech Commands dataset.
@@ -109,19 +122,36 @@ pub struct SpeechItem {
 /// - test: 4,890 audio files
 /// - validation: 9,982 audio files
 pub struct SpeechCommandsDataset {
-    dataset: MappedDataset,
+    dataset: Box<dyn Dataset<SpeechItem>>,
 }
 
 impl SpeechCommandsDataset {
-    /// Create a new dataset with the given split.
+    /// Create a new dataset with the given split, downmixing multi-channel clips to mono.
     pub fn new(split: &str) -> Self {
+        Self::with_channel_mode(split, ChannelMode::DownmixToMono)
+    }
+
+    /// Like [Self::new], with explicit control over how multi-channel clips are handled.
+    pub fn with_channel_mode(split: &str, channel_mode: ChannelMode) -> Self {
         let dataset: SqliteDataset<SpeechItemRaw> =
             HuggingfaceDatasetLoader::new("speech_commands")
                 .with_subset("v0.02")
                 .dataset(split)
                 .unwrap();
-        let dataset = MapperDataset::new(dataset, ConvertSamples);
-        Self { dataset }
+        let dataset: MappedDataset = MapperDataset::new(dataset, ConvertSamples { channel_mode });
+        Self {
+            dataset: Box::new(dataset),
+        }
+    }
+
+    /// Create a new dataset with the given split, resampling every clip to `sample_rate` Hz so
+    /// batches don't mix clips recorded at different rates.
+    pub fn with_sample_rate(split: &str, sample_rate: usize) -> Self {
+        let base = Self::new(split);
+        let dataset = MapperDataset::new(base.dataset, Resample::new(sample_rate));
+        Self {
+            dataset: Box::new(dataset),
+        }
     }
 
     /// Create a new dataset with the train split.
@@ -139,6 +169,21 @@ impl SpeechCommandsDataset {
         Self::new("validation")
     }
 
+    /// Like [Self::train], resampling every clip to `sample_rate` Hz.
+    pub fn train_with_sample_rate(sample_rate: usize) -> Self {
+        Self::with_sample_rate("train", sample_rate)
+    }
+
+    /// Like [Self::test], resampling every clip to `sample_rate` Hz.
+    pub fn test_with_sample_rate(sample_rate: usize) -> Self {
+        Self::with_sample_rate("test", sample_rate)
+    }
+
+    /// Like [Self::validation], resampling every clip to `sample_rate` Hz.
+    pub fn validation_with_sample_rate(sample_rate: usize) -> Self {
+        Self::with_sample_rate("validation", sample_rate)
+    }
+
     /// Returns the number of classes in the dataset
     pub fn num_classes() -> usize {
         22 // 10 command words + 10 digits + 1 silence + 1 other
@@ -156,7 +201,10 @@ impl Dataset<SpeechItem> for SpeechCommandsDataset {
 }
 
 /// Mapper converting audio bytes into audio samples and the label to enum class.
-struct ConvertSamples;
+#[derive(Default)]
+struct ConvertSamples {
+    channel_mode: ChannelMode,
+}
 
 impl ConvertSamples {
     /// Convert label to enum class and select the target classes.
@@ -175,24 +223,196 @@ impl ConvertSamples {
     }
 
     /// Convert audio bytes into samples of floats [-1.0, 1.0].
-    fn to_audiosamples(bytes: &Vec<u8>) -> (Vec<f32>, usize) {
-        let reader = WavReader::new(bytes.as_slice()).unwrap();
-        let spec = reader.spec();
+    fn to_audiosamples(bytes: &Vec<u8>, channel_mode: ChannelMode) -> (Vec<f32>, usize) {
+        decode_wav(bytes, channel_mode)
+    }
+}
+
+/// Decode WAV bytes into samples of floats [-1.0, 1.0] and the file's sample rate.
+///
+/// Branches on `spec.sample_format`: `Int` samples are read at their native bit depth and
+/// normalized by the true full-scale value for that depth (8-bit PCM is unsigned, offset-binary
+/// with 128 as the zero point); `Float` samples are already in [-1.0, 1.0] and are read as-is.
+/// Multi-channel files are handled per `channel_mode`.
+pub(crate) fn decode_wav(bytes: &[u8], channel_mode: ChannelMode) -> (Vec<f32>, usize) {
+    let mut reader = WavReader::new(bytes).unwrap();
+    let spec = reader.spec();
+    let sample_rate = spec.sample_rate as usize;
+    let channels = spec.channels as usize;
+
+    let samples: Vec<f32> = match spec.sample_format {
+        SampleFormat::Float => reader.samples::<f32>().filter_map(Result::ok).collect(),
+        SampleFormat::Int => match spec.bits_per_sample {
+            // 8-bit WAV PCM is stored as unsigned, offset-binary with 128 as the zero
+            // point; hound's `i8` reader already applies that bias, giving values in
+            // [-128, 127] centered at 0.
+            8 => reader
+                .samples::<i8>()
+                .filter_map(Result::ok)
+                .map(|sample| sample as f32 / 128.0)
+                .collect(),
+            16 => reader
+                .samples::<i16>()
+                .filter_map(Result::ok)
+                .map(|sample| sample as f32 / 32_768.0)
+                .collect(),
+            bits @ (24 | 32) => {
+                let max_value = (1i64 << (bits - 1)) as f32;
+                reader
+                    .samples::<i32>()
+                    .filter_map(Result::ok)
+                    .map(|sample| sample as f32 / max_value)
+                    .collect()
+            }
+            bits => panic!("unsupported WAV bit depth: {bits}"),
+        },
+    };
+
+    let audio_samples = match (channel_mode, channels) {
+        (ChannelMode::DownmixToMono, channels) if channels > 1 => samples
+            .chunks(channels)
+            .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+            .collect(),
+        _ => samples,
+    };
+
+    (audio_samples, sample_rate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use hound::{WavSpec, WavWriter};
+    use std::io::Cursor;
+
+    fn write_wav(
+        spec: WavSpec,
+        write_samples: impl FnOnce(&mut WavWriter<Cursor<&mut Vec<u8>>>),
+    ) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        {
+            let mut writer = WavWriter::new(Cursor::new(&mut bytes), spec).unwrap();
+            write_samples(&mut writer);
+            writer.finalize().unwrap();
+        }
+        bytes
+    }
+
+    fn mono_spec(bits_per_sample: u16, sample_format: SampleFormat) -> WavSpec {
+        WavSpec {
+            channels: 1,
+            sample_rate: 16000,
+            bits_per_sample,
+            sample_format,
+        }
+    }
+
+    #[test]
+    fn decode_wav_normalizes_8_bit_int() {
+        let bytes = write_wav(mono_spec(8, SampleFormat::Int), |writer| {
+            for sample in [-128i8, 0, 127] {
+                writer.write_sample(sample).unwrap();
+            }
+        });
+
+        let (samples, sample_rate) = decode_wav(&bytes, ChannelMode::DownmixToMono);
+
+        assert_eq!(sample_rate, 16000);
+        assert_eq!(samples, vec![-1.0, 0.0, 127.0 / 128.0]);
+    }
+
+    #[test]
+    fn decode_wav_normalizes_16_bit_int() {
+        let bytes = write_wav(mono_spec(16, SampleFormat::Int), |writer| {
+            for sample in [i16::MIN, 0, i16::MAX] {
+                writer.write_sample(sample).unwrap();
+            }
+        });
+
+        let (samples, _) = decode_wav(&bytes, ChannelMode::DownmixToMono);
+
+        assert_eq!(samples, vec![-1.0, 0.0, i16::MAX as f32 / 32_768.0]);
+    }
 
-        // Maximum value of the audio samples (using bit shift to raise 2 to the power of bits per sample).
-        let max_value = (1 << (spec.bits_per_sample - 1)) as f32;
+    #[test]
+    fn decode_wav_normalizes_24_bit_int() {
+        let max_value = (1i64 << 23) as f32;
+        let bytes = write_wav(mono_spec(24, SampleFormat::Int), |writer| {
+            for sample in [-(max_value as i32), 0, max_value as i32 - 1] {
+                writer.write_sample(sample).unwrap();
+            }
+        });
 
-        // The sample rate of the audio.
-        let sample_rate = spec.sample_rate as usize;
+        let (samples, _) = decode_wav(&bytes, ChannelMode::DownmixToMono);
 
-        // Convert the audio samples to floats [-1.0, 1.0].
-        let audio_samples: Vec<f32> = reader
-            .into_samples::<i32>()
-            .filter_map(Result::ok)
-            .map(|sample| sample as f32 / max_value)
-            .collect();
+        assert_eq!(samples, vec![-1.0, 0.0, (max_value - 1.0) / max_value]);
+    }
+
+    #[test]
+    fn decode_wav_normalizes_32_bit_int() {
+        let max_value = (1i64 << 31) as f32;
+        let bytes = write_wav(mono_spec(32, SampleFormat::Int), |writer| {
+            for sample in [i32::MIN, 0, i32::MAX] {
+                writer.write_sample(sample).unwrap();
+            }
+        });
+
+        let (samples, _) = decode_wav(&bytes, ChannelMode::DownmixToMono);
+
+        assert_eq!(samples, vec![-1.0, 0.0, i32::MAX as f32 / max_value]);
+    }
+
+    #[test]
+    fn decode_wav_passes_through_float_samples() {
+        let bytes = write_wav(mono_spec(32, SampleFormat::Float), |writer| {
+            for sample in [-1.0f32, 0.0, 0.5] {
+                writer.write_sample(sample).unwrap();
+            }
+        });
 
-        (audio_samples, sample_rate)
+        let (samples, _) = decode_wav(&bytes, ChannelMode::DownmixToMono);
+
+        assert_eq!(samples, vec![-1.0, 0.0, 0.5]);
+    }
+
+    #[test]
+    fn decode_wav_downmixes_stereo_to_mono() {
+        let spec = WavSpec {
+            channels: 2,
+            ..mono_spec(16, SampleFormat::Int)
+        };
+        let bytes = write_wav(spec, |writer| {
+            for sample in [i16::MIN, i16::MAX, 0, 0] {
+                writer.write_sample(sample).unwrap();
+            }
+        });
+
+        let (samples, _) = decode_wav(&bytes, ChannelMode::DownmixToMono);
+
+        // Frame 1: (-1.0 + MAX/32768) / 2 ~= 0.0, frame 2: 0.0.
+        assert_eq!(samples.len(), 2);
+        assert!(samples[0].abs() < 1e-3);
+        assert_eq!(samples[1], 0.0);
+    }
+
+    #[test]
+    fn decode_wav_keeps_stereo_interleaved() {
+        let spec = WavSpec {
+            channels: 2,
+            ..mono_spec(16, SampleFormat::Int)
+        };
+        let bytes = write_wav(spec, |writer| {
+            for sample in [i16::MIN, i16::MAX, 0, 0] {
+                writer.write_sample(sample).unwrap();
+            }
+        });
+
+        let (samples, _) = decode_wav(&bytes, ChannelMode::Interleaved);
+
+        assert_eq!(samples.len(), 4);
+        assert_eq!(samples[0], -1.0);
+        assert_eq!(samples[1], i16::MAX as f32 / 32_768.0);
     }
 }
 
@@ -203,7 +423,8 @@ impl Mapper<SpeechItemRaw, SpeechItem> for ConvertSamples {
     /// Note: The orginal label is also stored in the `label_original` field for debugging
     /// and remapping if needed.
     fn map(&self, item: &SpeechItemRaw) -> SpeechItem {
-        let (audio_samples, sample_rate) = Self::to_audiosamples(&item.audio_bytes);
+        let (audio_samples, sample_rate) =
+            Self::to_audiosamples(&item.audio_bytes, self.channel_mode);
 
         // Convert the label to enum class, with the target words, other and silence classes.
         let label = Self::word_choice(item.label);
@@ -218,4 +439,4 @@ impl Mapper<SpeechItemRaw, SpeechItem> for ConvertSamples {
             label_original,
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,293 @@
+use crate::transform::Mapper;
+
+use super::SpeechItem;
+
+/// Configuration for [TrimSilence].
+#[derive(Clone, Debug)]
+pub struct TrimSilenceConfig {
+    /// Frame size in milliseconds used for energy estimation.
+    pub frame_ms: usize,
+
+    /// Hop size in milliseconds between consecutive frames.
+    pub hop_ms: usize,
+
+    /// Threshold in dB relative to the peak frame energy (so normally negative): frames whose
+    /// energy minus the peak energy falls below it are considered silence, e.g. `-40.0` marks
+    /// frames more than 40 dB quieter than the peak as silence.
+    pub energy_threshold_db: f32,
+
+    /// Number of frames below threshold, adjacent to active speech, that still count as active
+    /// (avoids clipping plosives and quiet transients).
+    pub hangover_frames: usize,
+}
+
+impl Default for TrimSilenceConfig {
+    fn default() -> Self {
+        Self {
+            frame_ms: 25,
+            hop_ms: 10,
+            energy_threshold_db: -40.0,
+            hangover_frames: 4,
+        }
+    }
+}
+
+/// Mapper trimming leading/trailing silence from a [SpeechItem] based on short-time energy
+/// (a simple form of voice-activity detection).
+pub struct TrimSilence {
+    config: TrimSilenceConfig,
+    mode: TrimSilenceMode,
+}
+
+/// Output mode for [TrimSilence].
+#[derive(Clone, Debug)]
+pub enum TrimSilenceMode {
+    /// Keep the sample span from the first to the last active frame, optionally padded by
+    /// `pad_ms` milliseconds on each side.
+    Trim { pad_ms: usize },
+
+    /// Return a fixed-length chunk of `chunk_len` samples centered on the detected speech, so
+    /// downstream batching stays uniform. Positions of the chunk that fall outside the clip are
+    /// filled with zeros.
+    FixedChunk { chunk_len: usize },
+}
+
+impl TrimSilence {
+    /// Create a new mapper trimming silence and keeping only the detected speech span, padded
+    /// by `pad_ms` milliseconds on each side.
+    pub fn new(config: TrimSilenceConfig, pad_ms: usize) -> Self {
+        Self {
+            config,
+            mode: TrimSilenceMode::Trim { pad_ms },
+        }
+    }
+
+    /// Create a new mapper returning a fixed-length chunk of `chunk_len` samples centered on the
+    /// detected speech.
+    pub fn fixed_chunk(config: TrimSilenceConfig, chunk_len: usize) -> Self {
+        Self {
+            config,
+            mode: TrimSilenceMode::FixedChunk { chunk_len },
+        }
+    }
+}
+
+impl Mapper<SpeechItem, SpeechItem> for TrimSilence {
+    fn map(&self, item: &SpeechItem) -> SpeechItem {
+        let frame_len = self.config.frame_ms * item.sample_rate / 1000;
+        let hop_len = self.config.hop_ms * item.sample_rate / 1000;
+
+        let span = active_speech_span(&item.audio_samples, frame_len, hop_len, &self.config);
+
+        let audio_samples = match span {
+            Some((start, end)) => match self.mode {
+                TrimSilenceMode::Trim { pad_ms } => {
+                    let pad = pad_ms * item.sample_rate / 1000;
+                    let start = start.saturating_sub(pad);
+                    let end = usize::min(end + pad, item.audio_samples.len());
+                    item.audio_samples[start..end].to_vec()
+                }
+                TrimSilenceMode::FixedChunk { chunk_len } => {
+                    centered_chunk(&item.audio_samples, start, end, chunk_len)
+                }
+            },
+            // No speech detected: keep the clip as-is (Trim) or as a centered chunk (FixedChunk).
+            None => match self.mode {
+                TrimSilenceMode::Trim { .. } => item.audio_samples.clone(),
+                TrimSilenceMode::FixedChunk { chunk_len } => {
+                    centered_chunk(&item.audio_samples, 0, item.audio_samples.len(), chunk_len)
+                }
+            },
+        };
+
+        SpeechItem {
+            audio_samples,
+            sample_rate: item.sample_rate,
+            label: item.label,
+            label_original: item.label_original,
+        }
+    }
+}
+
+/// Find the `[start, end)` sample span covering detected speech, or `None` if no frame is active.
+fn active_speech_span(
+    samples: &[f32],
+    frame_len: usize,
+    hop_len: usize,
+    config: &TrimSilenceConfig,
+) -> Option<(usize, usize)> {
+    if samples.is_empty() || frame_len == 0 || hop_len == 0 {
+        return None;
+    }
+
+    let n_frames = if samples.len() >= frame_len {
+        1 + (samples.len() - frame_len) / hop_len
+    } else {
+        1
+    };
+
+    let frame_energies_db: Vec<f32> = (0..n_frames)
+        .map(|i| {
+            let start = usize::min(i * hop_len, samples.len());
+            let end = usize::min(start + frame_len, samples.len());
+            let frame = &samples[start..end];
+
+            let mean_square = if frame.is_empty() {
+                0.0
+            } else {
+                frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32
+            };
+            mean_square.sqrt()
+        })
+        .map(|rms| 20.0 * (rms + 1e-10).log10())
+        .collect();
+
+    let peak_db = frame_energies_db
+        .iter()
+        .cloned()
+        .fold(f32::NEG_INFINITY, f32::max);
+
+    let mut active: Vec<bool> = frame_energies_db
+        .iter()
+        .map(|db| db - peak_db >= config.energy_threshold_db)
+        .collect();
+
+    // Apply hangover: frames within `hangover_frames` of an active frame stay active.
+    let mut with_hangover = active.clone();
+    for i in 0..active.len() {
+        if active[i] {
+            let lo = i.saturating_sub(config.hangover_frames);
+            let hi = usize::min(i + config.hangover_frames + 1, active.len());
+            for frame in with_hangover.iter_mut().take(hi).skip(lo) {
+                *frame = true;
+            }
+        }
+    }
+    active = with_hangover;
+
+    let first = active.iter().position(|&is_active| is_active)?;
+    let last = active.iter().rposition(|&is_active| is_active)?;
+
+    let start = first * hop_len;
+    let end = usize::min(last * hop_len + frame_len, samples.len());
+
+    Some((start, end))
+}
+
+/// Extract a fixed-length chunk of `chunk_len` samples centered on `[start, end)`, filling with
+/// zeros wherever the chunk extends before the first or past the last sample.
+fn centered_chunk(samples: &[f32], start: usize, end: usize, chunk_len: usize) -> Vec<f32> {
+    let center = (start + end) / 2;
+    let half = chunk_len / 2;
+    let chunk_start = center as isize - half as isize;
+
+    (0..chunk_len)
+        .map(|i| {
+            let index = chunk_start + i as isize;
+            if index >= 0 && (index as usize) < samples.len() {
+                samples[index as usize]
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::SpeechCommandClass;
+
+    fn silence(len: usize) -> Vec<f32> {
+        vec![0.0; len]
+    }
+
+    fn tone(len: usize, amplitude: f32) -> Vec<f32> {
+        (0..len)
+            .map(|n| amplitude * (0.1 * n as f32).sin())
+            .collect()
+    }
+
+    #[test]
+    fn active_speech_span_is_none_for_empty_or_zero_sized_frames() {
+        let config = TrimSilenceConfig::default();
+        assert_eq!(active_speech_span(&[], 400, 160, &config), None);
+        assert_eq!(active_speech_span(&tone(1000, 1.0), 0, 160, &config), None);
+        assert_eq!(active_speech_span(&tone(1000, 1.0), 400, 0, &config), None);
+    }
+
+    #[test]
+    fn active_speech_span_is_none_for_pure_silence() {
+        let config = TrimSilenceConfig::default();
+        let samples = silence(1600);
+        assert_eq!(active_speech_span(&samples, 400, 160, &config), None);
+    }
+
+    #[test]
+    fn active_speech_span_finds_a_tone_surrounded_by_silence() {
+        let config = TrimSilenceConfig::default();
+        let mut samples = silence(1600);
+        let burst_start = 1600;
+        let burst = tone(1600, 1.0);
+        samples.extend_from_slice(&burst);
+        samples.extend(silence(1600));
+
+        let (start, end) = active_speech_span(&samples, 400, 160, &config).unwrap();
+
+        // The hangover and frame/hop granularity mean the span is approximate, but it must
+        // bracket the burst and stay within the clip.
+        assert!(start <= burst_start);
+        assert!(end > burst_start + burst.len() - 160);
+        assert!(end <= samples.len());
+    }
+
+    #[test]
+    fn centered_chunk_extracts_requested_length() {
+        let samples = tone(1000, 1.0);
+        let chunk = centered_chunk(&samples, 400, 600, 200);
+        assert_eq!(chunk.len(), 200);
+        assert_eq!(&chunk, &samples[400..600]);
+    }
+
+    #[test]
+    fn centered_chunk_zero_pads_when_span_is_near_the_edges() {
+        let samples = tone(100, 1.0);
+        let chunk = centered_chunk(&samples, 0, 20, 200);
+
+        assert_eq!(chunk.len(), 200);
+        // Centered on sample 10 with half-width 100, so the chunk starts before sample 0.
+        assert!(chunk[0] == 0.0);
+    }
+
+    #[test]
+    fn trim_silence_trim_mode_drops_leading_and_trailing_silence() {
+        let mapper = TrimSilence::new(TrimSilenceConfig::default(), 0);
+        let mut audio_samples = silence(1600);
+        audio_samples.extend(tone(1600, 1.0));
+        audio_samples.extend(silence(1600));
+
+        let item = SpeechItem {
+            audio_samples,
+            sample_rate: 16000,
+            label: SpeechCommandClass::Yes,
+            label_original: SpeechCommandClass::Yes,
+        };
+
+        let trimmed = mapper.map(&item);
+        assert!(trimmed.audio_samples.len() < item.audio_samples.len());
+    }
+
+    #[test]
+    fn trim_silence_fixed_chunk_mode_returns_exact_length() {
+        let mapper = TrimSilence::fixed_chunk(TrimSilenceConfig::default(), 4000);
+        let item = SpeechItem {
+            audio_samples: tone(4800, 1.0),
+            sample_rate: 16000,
+            label: SpeechCommandClass::Yes,
+            label_original: SpeechCommandClass::Yes,
+        };
+
+        let chunked = mapper.map(&item);
+        assert_eq!(chunked.audio_samples.len(), 4000);
+    }
+}
@@ -222,11 +222,29 @@ impl<R: Runtime> FusionKernel<R> {
 
 impl<R: Runtime> Kernel for FusionKernel<R> {
     fn source(&self) -> SourceTemplate {
+        let cache_config = kernel_cache::config();
+        let cache_key = cache_config.enabled.then(|| {
+            // Fold in the compiler type so distinct backends sharing a cache directory can't
+            // collide on the same on-disk entry.
+            kernel_cache::cache_key(core::any::type_name::<R::Compiler>(), &self.id())
+        });
+
+        if let Some(key) = &cache_key {
+            if let Some(source) = kernel_cache::read(&cache_config.directory, key) {
+                return SourceTemplate::new(source);
+            }
+        }
+
         log::info!("Compiling ... {:?}", self.id());
         let compiled = Compilation::new(self.info.as_ref().clone()).compile(self.settings.clone());
         let compiled = <R::Compiler as Compiler>::compile(compiled);
+        let source = compiled.to_string();
+
+        if let Some(key) = &cache_key {
+            kernel_cache::write(&cache_config.directory, key, &source);
+        }
 
-        SourceTemplate::new(compiled.to_string())
+        SourceTemplate::new(source)
     }
 
     fn id(&self) -> String {
@@ -296,3 +314,199 @@ fn process_inputs_outputs<'a, R: Runtime>(
         outputs_description_updated,
     )
 }
+
+/// Persistent, on-disk cache of compiled [FusionKernel] sources, keyed by [FusionKernel::id].
+///
+/// Disabled by default; enabled via the `BURN_JIT_KERNEL_CACHE_DIR` environment variable, to
+/// avoid repeatedly paying for codegen and backend compilation of the same fused kernel across
+/// process restarts. [kernel_cache::configure] is the programmatic equivalent of that env var —
+/// no runtime/backend config in this crate calls it yet, so today it's only reachable from code
+/// within this crate that imports `kernel_cache` directly. Wiring a runtime/backend's own enable
+/// flag and cache directory through `configure` at startup is the intended next step once such a
+/// config path exists.
+/// `pub(crate)` so that config code, wherever it ends up, can reach it as
+/// `crate::fusion::kernel::kernel_cache::{configure, KernelCacheConfig}`.
+pub(crate) mod kernel_cache {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    use std::path::{Path, PathBuf};
+    use std::sync::{OnceLock, RwLock};
+
+    /// Bump this when codegen or the backend compiler could produce different output for the
+    /// same kernel id, so stale on-disk entries from an older compiler get invalidated instead
+    /// of being served back verbatim.
+    const CACHE_VERSION: u32 = 1;
+
+    #[derive(Clone, Debug)]
+    pub struct KernelCacheConfig {
+        pub enabled: bool,
+        pub directory: PathBuf,
+    }
+
+    impl Default for KernelCacheConfig {
+        fn default() -> Self {
+            match std::env::var_os("BURN_JIT_KERNEL_CACHE_DIR") {
+                Some(directory) => Self {
+                    enabled: true,
+                    directory: PathBuf::from(directory),
+                },
+                None => Self {
+                    enabled: false,
+                    directory: std::env::temp_dir().join("burn-jit-kernel-cache"),
+                },
+            }
+        }
+    }
+
+    fn config_cell() -> &'static RwLock<KernelCacheConfig> {
+        static CONFIG: OnceLock<RwLock<KernelCacheConfig>> = OnceLock::new();
+        CONFIG.get_or_init(|| RwLock::new(KernelCacheConfig::default()))
+    }
+
+    /// Read the current kernel cache configuration.
+    pub fn config() -> KernelCacheConfig {
+        config_cell().read().unwrap().clone()
+    }
+
+    /// Override the kernel cache configuration for the remainder of the process. Exposed so a
+    /// runtime/backend can wire its own config (enable flag, cache directory) through at
+    /// startup.
+    pub fn configure(config: KernelCacheConfig) {
+        *config_cell().write().unwrap() = config;
+    }
+
+    /// Build the on-disk cache key for a kernel `id` (already uniquely folding in settings and
+    /// the logical kernel id, see [FusionKernel::id](super::FusionKernel::id)), scoped to
+    /// `compiler` (its type name) so distinct backends sharing a cache directory never collide
+    /// on the same entry.
+    pub fn cache_key(compiler: &str, id: &str) -> String {
+        let mut hasher = DefaultHasher::new();
+        CACHE_VERSION.hash(&mut hasher);
+        compiler.hash(&mut hasher);
+        id.hash(&mut hasher);
+        format!("{:016x}", hasher.finish())
+    }
+
+    /// Look up a previously cached compiled source for `key` under `directory`.
+    pub fn read(directory: &Path, key: &str) -> Option<String> {
+        std::fs::read_to_string(directory.join(key)).ok()
+    }
+
+    /// Write a compiled source for `key` under `directory`, creating the directory if needed.
+    ///
+    /// Writes to a sibling temporary file first and renames it into place, so a concurrent
+    /// [read] never observes a partially-written entry. Failures are ignored: the cache is a
+    /// best-effort optimization, not a correctness requirement.
+    pub fn write(directory: &Path, key: &str, source: &str) {
+        use std::sync::atomic::{AtomicU64, Ordering};
+
+        // Unique per process and per call, so concurrent writers never share a temporary file.
+        static WRITE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        if std::fs::create_dir_all(directory).is_ok() {
+            let target = directory.join(key);
+            let n = WRITE_COUNTER.fetch_add(1, Ordering::Relaxed);
+            let tmp = directory.join(format!("{key}.tmp-{}-{n}", std::process::id()));
+
+            if std::fs::write(&tmp, source).is_ok() {
+                let _ = std::fs::rename(&tmp, &target);
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::sync::atomic::{AtomicU64, Ordering};
+
+        // Each test gets its own directory so concurrent test threads never race on the same
+        // on-disk entries.
+        fn temp_dir() -> PathBuf {
+            static COUNTER: AtomicU64 = AtomicU64::new(0);
+            let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+            std::env::temp_dir().join(format!(
+                "burn-jit-kernel-cache-test-{}-{n}",
+                std::process::id()
+            ))
+        }
+
+        #[test]
+        fn read_after_write_round_trips() {
+            let directory = temp_dir();
+            let key = cache_key("compiler::Wgsl", "kernel-1");
+
+            assert_eq!(read(&directory, &key), None);
+
+            write(&directory, &key, "compiled source");
+            assert_eq!(read(&directory, &key).as_deref(), Some("compiled source"));
+
+            let _ = std::fs::remove_dir_all(&directory);
+        }
+
+        #[test]
+        fn read_misses_until_populated() {
+            let directory = temp_dir();
+            let key = cache_key("compiler::Cuda", "kernel-2");
+
+            assert_eq!(read(&directory, &key), None);
+            write(&directory, &key, "first version");
+            assert_eq!(read(&directory, &key).as_deref(), Some("first version"));
+
+            write(&directory, &key, "second version");
+            assert_eq!(read(&directory, &key).as_deref(), Some("second version"));
+
+            let _ = std::fs::remove_dir_all(&directory);
+        }
+
+        #[test]
+        fn cache_key_changes_with_compiler_type() {
+            let a = cache_key("compiler::Wgsl", "kernel-1");
+            let b = cache_key("compiler::Cuda", "kernel-1");
+            assert_ne!(a, b);
+        }
+
+        #[test]
+        fn cache_key_changes_with_kernel_id() {
+            let a = cache_key("compiler::Wgsl", "kernel-1");
+            let b = cache_key("compiler::Wgsl", "kernel-2");
+            assert_ne!(a, b);
+        }
+
+        #[test]
+        fn cache_key_changes_when_cache_version_bumps() {
+            // `CACHE_VERSION` is a const, so this recomputes the key as if it had been bumped,
+            // guarding against someone folding it out of `cache_key` by accident.
+            let mut hasher = DefaultHasher::new();
+            (CACHE_VERSION + 1).hash(&mut hasher);
+            "compiler::Wgsl".hash(&mut hasher);
+            "kernel-1".hash(&mut hasher);
+            let bumped = format!("{:016x}", hasher.finish());
+
+            assert_ne!(bumped, cache_key("compiler::Wgsl", "kernel-1"));
+        }
+
+        #[test]
+        fn default_config_is_disabled_without_the_env_var() {
+            // This test assumes `BURN_JIT_KERNEL_CACHE_DIR` isn't set in the test environment.
+            if std::env::var_os("BURN_JIT_KERNEL_CACHE_DIR").is_none() {
+                assert!(!KernelCacheConfig::default().enabled);
+            }
+        }
+
+        #[test]
+        fn configure_overrides_the_process_wide_config() {
+            let directory = temp_dir();
+            configure(KernelCacheConfig {
+                enabled: true,
+                directory: directory.clone(),
+            });
+
+            let read_back = config();
+            assert!(read_back.enabled);
+            assert_eq!(read_back.directory, directory);
+
+            // Leave the process-wide config disabled again for any test that runs after this one.
+            configure(KernelCacheConfig::default());
+        }
+    }
+}